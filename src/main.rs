@@ -1,13 +1,23 @@
 // std
-use std::io::{Error, Stdout};
+use std::io::{BufRead, BufReader, Error, Stdout, Write};
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::os::unix::process::CommandExt;
 use std::path::{Path, PathBuf};
-use std::{collections::HashSet, env, io, process::Command, process::Stdio, result::Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+use std::{
+    collections::HashSet, env, fs, io, process::Command, process::Stdio, result::Result, thread,
+};
 // dirs
 use dirs::home_dir;
+// signal forwarding for the foreground child and pseudo-terminal allocation
+use libc::{kill, pid_t, SIGINT};
 
 // cross-platform backend
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    cursor::Show,
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -22,10 +32,91 @@ use ratatui::{
     Terminal,
 };
 
+/// Maximum number of commands kept in the persisted history file.
+const HISTORY_LIMIT: usize = 1000;
+
+/// Number of lines a PageUp/PageDown keystroke or a single mouse wheel notch scrolls.
+const SCROLL_STEP: u16 = 10;
+
+/// How long the main loop waits for a keystroke before checking for new
+/// streamed command output.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A message sent from the background threads of a running command: output
+/// lines from the stdout/stderr readers, `Done` from the thread that waits on
+/// the child.
+enum StreamMsg {
+    Line(String),
+    Done,
+}
+
+/// Interactive programs that expect a real tty even though they are not
+/// registered via `:fullscreen`. Matched against the first word's basename.
+const KNOWN_FULLSCREEN: &[&str] = &[
+    "vim", "vi", "nvim", "nano", "pico", "emacs", "less", "more", "most", "man", "top", "htop",
+    "btop", "ssh", "mutt", "tmux", "screen",
+];
+
+/// Set by `handle_winch` and polled from `run_in_pty`'s event loop, since a
+/// signal handler can only safely do this much work.
+static WINCH_RECEIVED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn handle_winch(_: libc::c_int) {
+    WINCH_RECEIVED.store(true, Ordering::SeqCst);
+}
+
+/// Reads the real terminal's current size via `TIOCGWINSZ` on stdin
+fn current_winsize() -> libc::winsize {
+    let mut ws: libc::winsize = unsafe { std::mem::zeroed() };
+    unsafe {
+        libc::ioctl(0, libc::TIOCGWINSZ, &mut ws);
+    }
+    ws
+}
+
+/// Pushes `ws` onto `fd` via `TIOCSWINSZ`; if `fd` is a pty, the kernel
+/// delivers SIGWINCH to its foreground process group as a side effect
+fn apply_winsize(fd: libc::c_int, ws: &libc::winsize) {
+    unsafe {
+        libc::ioctl(fd, libc::TIOCSWINSZ, ws);
+    }
+}
+
+/// RAII guard that restores the terminal to its normal state on drop, even when
+/// unwinding from a panic. Must be constructed right after `enable_raw_mode`.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    /// Leaves the alternate screen, disables raw mode and mouse capture, and
+    /// shows the cursor again. Safe to call more than once.
+    fn restore() {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, Show);
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        TerminalGuard::restore();
+    }
+}
+
 struct App {
     input: String,
     output: String,
-    fullscreen_commands: HashSet<&'static str>,
+    /// Fullscreen commands seeded with a few defaults and extended via
+    /// `:fullscreen`, either a bare program name (e.g. "vim") or a full
+    /// invocation prefix (e.g. "git commit")
+    fullscreen_commands: HashSet<String>,
+    history: Vec<String>,
+    history_idx: Option<usize>,
+    scroll: u16,
+    /// Character index of the caret within `input`
+    cursor: usize,
+    /// pid of the currently running foreground child, if any
+    child_pid: Option<pid_t>,
+    /// receives streamed output lines while a command is running
+    output_rx: Option<Receiver<StreamMsg>>,
 }
 
 impl App {
@@ -33,10 +124,43 @@ impl App {
         App {
             input: String::new(),
             output: String::new(),
-            fullscreen_commands: ["htop", "vim", "less", "top"].iter().cloned().collect(),
+            fullscreen_commands: ["htop", "vim", "less", "top"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            history: App::load_history(),
+            history_idx: None,
+            scroll: 0,
+            cursor: 0,
+            child_pid: None,
+            output_rx: None,
         }
     }
 
+    /// Returns the path to the persisted history file, if a home directory is known
+    fn history_path() -> Option<PathBuf> {
+        home_dir().map(|home| home.join(".rustty_history"))
+    }
+
+    /// Loads the command history ring from `~/.rustty_history`, oldest first
+    fn load_history() -> Vec<String> {
+        match App::history_path() {
+            Some(path) => fs::read_to_string(path)
+                .map(|contents| contents.lines().map(String::from).collect())
+                .unwrap_or_default(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Persists the command history ring to `~/.rustty_history`, capped to `HISTORY_LIMIT`
+    fn save_history(&self) {
+        let Some(path) = App::history_path() else {
+            return;
+        };
+        let start = self.history.len().saturating_sub(HISTORY_LIMIT);
+        let _ = fs::write(path, self.history[start..].join("\n"));
+    }
+
     /// Returns the current directory
     fn current_dir() -> PathBuf {
         let current_dir = env::current_dir().unwrap_or_else(|_| PathBuf::from("/"));
@@ -48,55 +172,420 @@ impl App {
         current_dir
     }
 
-    /// In charge of running commands that do not involve a full screen
+    /// Spawns a command that does not involve a full screen and streams its
+    /// stdout/stderr into `self.output` as it produces them, instead of
+    /// blocking the event loop until it exits.
     fn run_cmd(&mut self) {
-        let cmd = self.input.trim();
-        match Command::new("sh").arg("-c").arg(cmd).output() {
-            Ok(value) => {
-                if value.status.success() {
-                    self.output = String::from_utf8_lossy(&value.stdout).to_string();
-                } else {
-                    self.output = String::from_utf8_lossy(&value.stderr).to_string();
-                }
-            }
+        let cmd = self.input.trim().to_string();
+        self.output.clear();
+
+        // run as the leader of its own process group so forward_sigint can
+        // signal the whole group: `sh` itself ignores SIGINT while it waits
+        // on a foreground child, so signalling only its pid never reaches
+        // the thing actually doing the work (e.g. `ping`, `tail -f`)
+        let mut child = match Command::new("sh")
+            .arg("-c")
+            .arg(&cmd)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .process_group(0)
+            .spawn()
+        {
+            Ok(child) => child,
             Err(_) => {
                 self.output = format!("Error: Command '{}' not found", cmd);
+                return;
+            }
+        };
+
+        self.child_pid = Some(child.id() as pid_t);
+
+        let stdout = child.stdout.take().expect("piped stdout");
+        let stderr = child.stderr.take().expect("piped stderr");
+        let (tx, rx) = mpsc::channel();
+        self.output_rx = Some(rx);
+
+        let stdout_tx = tx.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if stdout_tx.send(StreamMsg::Line(line)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        let stderr_tx = tx.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if stderr_tx.send(StreamMsg::Line(line)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        thread::spawn(move || {
+            let _ = child.wait();
+            let _ = tx.send(StreamMsg::Done);
+        });
+    }
+
+    /// Drains any output streamed by a running command, appending new lines
+    /// to `self.output`. Called once per main loop iteration.
+    fn drain_output(&mut self) {
+        let Some(rx) = &self.output_rx else {
+            return;
+        };
+        let mut finished = false;
+        loop {
+            match rx.try_recv() {
+                Ok(StreamMsg::Line(line)) => {
+                    self.output.push_str(&line);
+                    self.output.push('\n');
+                }
+                // the child has exited, but its reader threads may still be
+                // sending its last lines, so keep the channel until they hang up
+                Ok(StreamMsg::Done) => self.child_pid = None,
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
             }
         }
+        if finished {
+            self.output_rx = None;
+        }
+    }
+
+    /// Sends SIGINT to the foreground child's whole process group if one is
+    /// running, so it reaches the leaf process `sh` is waiting on rather than
+    /// just `sh` itself. Returns whether a child was actually running and
+    /// thus received the signal.
+    fn forward_sigint(&self) -> bool {
+        match self.child_pid {
+            Some(pid) => {
+                unsafe {
+                    kill(-pid, SIGINT);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether `cmd` should run as a fullscreen/interactive program rather
+    /// than through the streaming `run_cmd` path. Checks, in order: a
+    /// user-registered full invocation prefix (`:fullscreen git commit`), a
+    /// user-registered bare program name, the built-in heuristic list of
+    /// known pagers/editors, and whether the program resolves to `$EDITOR`
+    /// or `$PAGER`.
+    fn is_fullscreen_command(&self, cmd: &str) -> bool {
+        if self
+            .fullscreen_commands
+            .iter()
+            .any(|registered| cmd == registered || cmd.starts_with(&format!("{} ", registered)))
+        {
+            return true;
+        }
+
+        let Some(program) = cmd.split_whitespace().next() else {
+            return false;
+        };
+        let name = Path::new(program)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(program);
+
+        if KNOWN_FULLSCREEN.contains(&name) {
+            return true;
+        }
+
+        ["EDITOR", "PAGER"].iter().any(|var| {
+            env::var(var).is_ok_and(|value| {
+                Path::new(value.split_whitespace().next().unwrap_or(&value))
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    == Some(name)
+            })
+        })
+    }
+
+    /// Registers `cmd` (a bare program name or a full invocation prefix) as a
+    /// fullscreen command for the `:fullscreen <cmd>` directive
+    fn register_fullscreen(&mut self, cmd: &str) {
+        self.fullscreen_commands.insert(cmd.to_string());
+        self.output = format!("Registered '{}' as a fullscreen command", cmd);
     }
 
-    /// Runs fullscreen commands
+    /// Runs fullscreen/interactive commands under a pseudo-terminal so their
+    /// raw-mode and job-control expectations are met even though rustty
+    /// itself owns the real tty
     fn run_fullscreen_cmd(&mut self) {
-        let cmd = self.input.trim();
+        let cmd = self.input.trim().to_string();
 
-        disable_raw_mode().expect("Failed to disable raw mode");
-        execute!(io::stdout(), LeaveAlternateScreen).expect("Failed to leave alternate screen");
+        // stay in raw mode so Ctrl+C and friends are forwarded as plain bytes
+        // into the pty instead of being caught by our own controlling tty;
+        // only the alternate screen and mouse capture need to step aside
+        execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)
+            .expect("Failed to leave alternate screen");
 
-        let mut child = Command::new("sh")
+        if let Err(e) = App::run_in_pty(&cmd) {
+            self.output = format!("Error running '{}' in a pty: {}", cmd, e);
+        }
+
+        execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)
+            .expect("Failed to enter alternate screen");
+    }
+
+    /// Allocates a pty, spawns `cmd` attached to its slave end, and shuttles
+    /// bytes between the real terminal and the pty master until the child exits
+    fn run_in_pty(cmd: &str) -> io::Result<()> {
+        let mut master: libc::c_int = 0;
+        let mut slave: libc::c_int = 0;
+        let mut ws = current_winsize();
+        let rc = unsafe {
+            libc::openpty(
+                &mut master,
+                &mut slave,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut ws,
+            )
+        };
+        if rc != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // forward live resizes of the real terminal to the pty for the
+        // duration of this call; curses/ncurses programs size themselves off
+        // TIOCGWINSZ on the slave and expect SIGWINCH when it changes
+        WINCH_RECEIVED.store(false, Ordering::SeqCst);
+        let prev_handler = unsafe { libc::signal(libc::SIGWINCH, handle_winch as libc::sighandler_t) };
+
+        let mut command = Command::new("sh");
+        command
             .arg("-c")
             .arg(cmd)
-            .stdin(Stdio::inherit())
-            .stdout(Stdio::inherit())
-            .spawn()
-            .expect("Failed to spawn command");
+            .stdin(unsafe { Stdio::from_raw_fd(libc::dup(slave)) })
+            .stdout(unsafe { Stdio::from_raw_fd(libc::dup(slave)) })
+            .stderr(unsafe { Stdio::from_raw_fd(libc::dup(slave)) });
+        // give the child a new session with the pty as its controlling
+        // terminal, so the pty turns Ctrl+C into SIGINT and resizes into
+        // SIGWINCH for it
+        unsafe {
+            command.pre_exec(|| {
+                if libc::setsid() == -1 || libc::ioctl(0, libc::TIOCSCTTY, 0) == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+        let child = command.spawn();
+        unsafe {
+            libc::close(slave);
+        }
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                unsafe {
+                    libc::signal(libc::SIGWINCH, prev_handler);
+                    libc::close(master);
+                }
+                return Err(e);
+            }
+        };
+
+        // non-blocking so a single thread can poll both the real stdin and
+        // the pty master without either side starving the other
+        unsafe {
+            let flags = libc::fcntl(master, libc::F_GETFL, 0);
+            libc::fcntl(master, libc::F_SETFL, flags | libc::O_NONBLOCK);
+        }
+
+        let stdin_fd = io::stdin().as_raw_fd();
+        let mut buf = [0u8; 4096];
+        loop {
+            if matches!(child.try_wait(), Ok(Some(_))) {
+                break;
+            }
+
+            if WINCH_RECEIVED.swap(false, Ordering::SeqCst) {
+                apply_winsize(master, &current_winsize());
+            }
+
+            let mut fds = [
+                libc::pollfd {
+                    fd: stdin_fd,
+                    events: libc::POLLIN,
+                    revents: 0,
+                },
+                libc::pollfd {
+                    fd: master,
+                    events: libc::POLLIN,
+                    revents: 0,
+                },
+            ];
+            let ready = unsafe { libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, 100) };
+            if ready <= 0 {
+                continue;
+            }
+
+            if fds[0].revents & libc::POLLIN != 0 {
+                let n = unsafe { libc::read(stdin_fd, buf.as_mut_ptr().cast(), buf.len()) };
+                if n > 0 {
+                    unsafe {
+                        libc::write(master, buf.as_ptr().cast(), n as usize);
+                    }
+                }
+            }
+            if fds[1].revents & libc::POLLIN != 0 {
+                let n = unsafe { libc::read(master, buf.as_mut_ptr().cast(), buf.len()) };
+                if n > 0 {
+                    io::stdout().write_all(&buf[..n as usize])?;
+                    io::stdout().flush()?;
+                }
+            }
+        }
 
         let _ = child.wait();
+        unsafe {
+            libc::signal(libc::SIGWINCH, prev_handler);
+            libc::close(master);
+        }
+        Ok(())
+    }
+
+    /// Walks the history ring, copying the selected entry into `self.input`
+    fn recall_history(&mut self, idx: usize) {
+        self.history_idx = Some(idx);
+        self.input = self.history[idx].clone();
+        self.cursor = self.input.chars().count();
+    }
+
+    /// Byte offset in `input` corresponding to the current character cursor
+    fn cursor_byte_idx(&self) -> usize {
+        self.input
+            .char_indices()
+            .nth(self.cursor)
+            .map(|(i, _)| i)
+            .unwrap_or(self.input.len())
+    }
 
-        enable_raw_mode().expect("Failed to enable raw mode");
-        execute!(io::stdout(), EnterAlternateScreen).expect("Failed to enter alternate screen");
+    /// Inserts `c` at the cursor and advances it
+    fn insert_char(&mut self, c: char) {
+        let idx = self.cursor_byte_idx();
+        self.input.insert(idx, c);
+        self.cursor += 1;
+    }
+
+    /// Deletes the character immediately before the cursor, if any
+    fn delete_char_before_cursor(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        self.cursor -= 1;
+        let idx = self.cursor_byte_idx();
+        self.input.remove(idx);
+    }
+
+    /// Deletes from the start of the previous word up to the cursor (Ctrl+W)
+    fn delete_word_before_cursor(&mut self) {
+        let chars: Vec<char> = self.input.chars().collect();
+        let mut start = self.cursor;
+        while start > 0 && chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        while start > 0 && !chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        let before: String = chars[..start].iter().collect();
+        let after: String = chars[self.cursor..].iter().collect();
+        self.input = before + &after;
+        self.cursor = start;
+    }
+
+    /// Deletes from the start of the line up to the cursor (Ctrl+U)
+    fn delete_to_line_start(&mut self) {
+        let chars: Vec<char> = self.input.chars().collect();
+        self.input = chars[self.cursor..].iter().collect();
+        self.cursor = 0;
+    }
+
+    /// Furthest the output pane can scroll down without running past the last line
+    fn max_scroll(&self) -> u16 {
+        self.output.lines().count().saturating_sub(1) as u16
+    }
+
+    /// Scrolls the output pane up (towards the start) by `amount` lines
+    fn scroll_up(&mut self, amount: u16) {
+        self.scroll = self.scroll.saturating_sub(amount);
+    }
+
+    /// Scrolls the output pane down (towards the end) by `amount` lines
+    fn scroll_down(&mut self, amount: u16) {
+        self.scroll = (self.scroll + amount).min(self.max_scroll());
     }
 
     /// Reads input commands and modifies the output accordingly
-    fn read(&mut self, key: KeyCode) {
-        match key {
-            KeyCode::Char(c) => {
-                self.input.push(c);
+    fn read(&mut self, code: KeyCode, modifiers: event::KeyModifiers) {
+        match (code, modifiers) {
+            (KeyCode::Char('w'), event::KeyModifiers::CONTROL) => {
+                self.delete_word_before_cursor();
+            }
+            (KeyCode::Char('u'), event::KeyModifiers::CONTROL) => {
+                self.delete_to_line_start();
+            }
+            (KeyCode::Char('l'), event::KeyModifiers::CONTROL) => {
+                self.output.clear();
+                self.scroll = 0;
             }
-            KeyCode::Backspace => {
-                self.input.pop();
+            (KeyCode::Char(c), _) => {
+                self.insert_char(c);
             }
-            KeyCode::Enter => {
+            (KeyCode::Backspace, _) => {
+                self.delete_char_before_cursor();
+            }
+            (KeyCode::Left, _) => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            (KeyCode::Right, _) => {
+                self.cursor = (self.cursor + 1).min(self.input.chars().count());
+            }
+            (KeyCode::Home, _) => {
+                self.cursor = 0;
+            }
+            (KeyCode::End, _) => {
+                self.cursor = self.input.chars().count();
+            }
+            (KeyCode::Up, _) if !self.history.is_empty() => {
+                let idx = match self.history_idx {
+                    Some(idx) => idx.saturating_sub(1),
+                    None => self.history.len() - 1,
+                };
+                self.recall_history(idx);
+            }
+            (KeyCode::Down, _) => match self.history_idx {
+                Some(idx) if idx + 1 < self.history.len() => self.recall_history(idx + 1),
+                Some(_) => {
+                    self.history_idx = None;
+                    self.input.clear();
+                    self.cursor = 0;
+                }
+                None => {}
+            },
+            (KeyCode::PageUp, _) => self.scroll_up(SCROLL_STEP),
+            (KeyCode::PageDown, _) => self.scroll_down(SCROLL_STEP),
+            (KeyCode::Enter, _) => {
                 let cmd = self.input.trim().to_string();
+                if self.child_pid.is_some() {
+                    // a streamed command is still running; starting another
+                    // would orphan it and steal Ctrl+C from forward_sigint
+                    return;
+                }
+                if !cmd.is_empty() {
+                    self.history.push(cmd.clone());
+                }
+                self.history_idx = None;
                 if cmd.starts_with("cd ") {
                     match env::set_current_dir(cmd[3..].trim()) {
                         Ok(_) => {}
@@ -104,12 +593,16 @@ impl App {
                     }
                 } else if cmd == "clear" {
                     self.output.clear();
-                } else if self.fullscreen_commands.contains(cmd.as_str()) {
+                } else if let Some(registration) = cmd.strip_prefix(":fullscreen ") {
+                    self.register_fullscreen(registration.trim());
+                } else if self.is_fullscreen_command(&cmd) {
                     self.run_fullscreen_cmd();
                 } else {
                     self.run_cmd();
                 }
                 self.input.clear();
+                self.cursor = 0;
+                self.scroll = 0;
             }
             _ => {}
         }
@@ -132,15 +625,25 @@ impl App {
                 // output area
                 let command_output = Paragraph::new(self.output.as_str())
                     .style(Style::default().fg(Color::White))
-                    .block(Block::default().borders(Borders::ALL).title("Output"));
+                    .block(Block::default().borders(Borders::ALL).title("Output"))
+                    .scroll((self.scroll, 0));
                 f.render_widget(command_output, chunks[0]);
 
-                // input area
-                let prompt = format!("{} > {}", App::current_dir().display(), self.input);
-                let input = Paragraph::new(Line::from(Span::styled(
-                    prompt,
-                    Style::default().fg(Color::Blue),
-                )))
+                // input area, with the caret rendered as a styled cell at self.cursor
+                let prefix = format!("{} > ", App::current_dir().display());
+                let chars: Vec<char> = self.input.chars().collect();
+                let before: String = chars[..self.cursor].iter().collect();
+                let at: String = chars.get(self.cursor).map(|c| c.to_string()).unwrap_or_else(|| " ".to_string());
+                let after: String = if self.cursor < chars.len() {
+                    chars[self.cursor + 1..].iter().collect()
+                } else {
+                    String::new()
+                };
+                let input = Paragraph::new(Line::from(vec![
+                    Span::styled(format!("{}{}", prefix, before), Style::default().fg(Color::Blue)),
+                    Span::styled(at, Style::default().fg(Color::Black).bg(Color::White)),
+                    Span::styled(after, Style::default().fg(Color::Blue)),
+                ]))
                 .style(Style::default().fg(Color::Green))
                 .block(Block::default().borders(Borders::ALL).title("Input"));
                 f.render_widget(input, chunks[1]);
@@ -152,6 +655,16 @@ impl App {
 fn main() -> Result<(), Error> {
     // setup
     enable_raw_mode().expect("Failed to enable raw mode");
+    let _guard = TerminalGuard;
+
+    // make sure a panic anywhere below (a child process, rendering, ...) still
+    // leaves the caller's shell in a usable state
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        TerminalGuard::restore();
+        default_hook(info);
+    }));
+
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
@@ -160,29 +673,39 @@ fn main() -> Result<(), Error> {
 
     // main loop
     loop {
+        // pull in any output streamed by a running command before redrawing
+        app.drain_output();
+
         // render
         app.render_ui(&mut terminal);
 
-        // react to input keystrokes
-        if let Event::Key(key_event) = event::read()? {
-            match key_event.code {
-                // exit the terminal with ctrl + d
-                KeyCode::Char('c') if key_event.modifiers == event::KeyModifiers::CONTROL => {
-                    break;
-                }
-                key => app.read(key),
+        // react to input keystrokes and mouse scrolling; poll instead of
+        // blocking so streamed output keeps the UI updating live
+        if event::poll(POLL_INTERVAL)? {
+            match event::read()? {
+                Event::Key(key_event) => match key_event.code {
+                    // ctrl+c interrupts a running foreground child; with nothing
+                    // running it exits rustty
+                    KeyCode::Char('c') if key_event.modifiers == event::KeyModifiers::CONTROL => {
+                        if !app.forward_sigint() {
+                            break;
+                        }
+                    }
+                    _ => app.read(key_event.code, key_event.modifiers),
+                },
+                Event::Mouse(mouse_event) => match mouse_event.kind {
+                    MouseEventKind::ScrollUp => app.scroll_up(SCROLL_STEP),
+                    MouseEventKind::ScrollDown => app.scroll_down(SCROLL_STEP),
+                    _ => {}
+                },
+                _ => {}
             }
         }
     }
 
-    // Cleanup and restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    // Persist history; the terminal itself is restored by `_guard` when it
+    // drops (and by the panic hook on a panic)
+    app.save_history();
 
     Ok(())
 }